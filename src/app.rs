@@ -0,0 +1,247 @@
+use std::sync::mpsc::Sender;
+
+use tui::{layout::Rect, widgets::TableState};
+
+use crate::cli::Resource;
+use crate::network::IoEvent;
+use crate::ui::Theme;
+
+pub struct TabsState {
+  pub titles: Vec<&'static str>,
+  pub index: usize,
+}
+
+impl TabsState {
+  pub fn new(titles: Vec<&'static str>) -> TabsState {
+    TabsState { titles, index: 0 }
+  }
+
+  /// Select `index` if it names one of `titles`, otherwise leave the current
+  /// selection untouched.
+  pub fn set_index(&mut self, index: usize) {
+    if index < self.titles.len() {
+      self.index = index;
+    }
+  }
+
+  pub fn next(&mut self) {
+    self.index = (self.index + 1) % self.titles.len();
+  }
+
+  pub fn previous(&mut self) {
+    if self.index > 0 {
+      self.index -= 1;
+    } else {
+      self.index = self.titles.len() - 1;
+    }
+  }
+}
+
+pub struct StatefulTable<T> {
+  pub state: TableState,
+  pub items: Vec<T>,
+}
+
+impl<T> Default for StatefulTable<T> {
+  fn default() -> StatefulTable<T> {
+    StatefulTable {
+      state: TableState::default(),
+      items: vec![],
+    }
+  }
+}
+
+impl<T> StatefulTable<T> {
+  pub fn new() -> StatefulTable<T> {
+    StatefulTable::default()
+  }
+
+  pub fn set_items(&mut self, items: Vec<T>) {
+    self.items = items;
+    if self.items.is_empty() {
+      self.state.select(None);
+    } else {
+      let i = self.state.selected().map_or(0, |i| i.min(self.items.len() - 1));
+      self.state.select(Some(i));
+    }
+  }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct CliInfo {
+  pub name: String,
+  pub version: String,
+  pub status: bool,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct ActiveContext {
+  pub name: String,
+  pub cluster: String,
+  pub user: String,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Namespace {
+  pub name: String,
+  pub status: String,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Pod {
+  pub namespace: String,
+  pub name: String,
+  pub ready: String,
+  pub status: String,
+  pub restarts: i32,
+  pub age: String,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Service {
+  pub namespace: String,
+  pub name: String,
+  pub type_: String,
+  pub cluster_ip: String,
+  pub external_ip: String,
+  pub ports: String,
+  pub age: String,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Node {
+  pub name: String,
+  pub status: String,
+  pub role: String,
+  pub version: String,
+  pub pods: i32,
+  pub cpu: String,
+  pub mem: String,
+  pub cpu_percent: String,
+  pub mem_percent: String,
+  pub age: String,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct NodeMetrics {
+  pub cpu_percent_i: f64,
+  pub mem_percent_i: f64,
+}
+
+pub struct App {
+  pub io_tx: Sender<IoEvent>,
+  pub should_quit: bool,
+  pub is_loading: bool,
+  pub enhanced_graphics: bool,
+  pub tick_until_poll: u64,
+  tick_count: u64,
+
+  pub size: Rect,
+  pub refresh: bool,
+
+  pub help_menu_max_lines: u32,
+  pub help_menu_offset: u32,
+  pub help_menu_page: u32,
+
+  pub theme: Theme,
+  pub show_info_bar: bool,
+  pub basic: bool,
+  /// While set, `on_tick` is a no-op: the displayed snapshot stays put so the
+  /// user can inspect it without new data scrolling things around.
+  pub frozen: bool,
+  /// While set, the selected resource table is given the whole terminal and
+  /// the tab bar/info bar are skipped entirely.
+  pub maximized: bool,
+  pub context_tabs: TabsState,
+
+  pub active_context: Option<ActiveContext>,
+  pub selected_ns: Option<String>,
+  pub namespaces: StatefulTable<Namespace>,
+  pub clis: Vec<CliInfo>,
+  pub node_metrics: Vec<NodeMetrics>,
+
+  pub pods: StatefulTable<Pod>,
+  pub services: StatefulTable<Service>,
+  pub nodes: StatefulTable<Node>,
+}
+
+impl App {
+  pub fn new(
+    io_tx: Sender<IoEvent>,
+    enhanced_graphics: bool,
+    tick_until_poll: u64,
+    default_resource: Resource,
+    basic: bool,
+    theme: Theme,
+  ) -> App {
+    let mut context_tabs = TabsState::new(vec!["Pods", "Services", "Nodes"]);
+    context_tabs.set_index(default_resource.tab_index());
+
+    App {
+      io_tx,
+      should_quit: false,
+      is_loading: false,
+      enhanced_graphics,
+      tick_until_poll,
+      tick_count: 0,
+      size: Rect::default(),
+      refresh: true,
+      help_menu_max_lines: 0,
+      help_menu_offset: 0,
+      help_menu_page: 0,
+      theme,
+      show_info_bar: true,
+      basic,
+      frozen: false,
+      maximized: false,
+      context_tabs,
+      active_context: None,
+      selected_ns: None,
+      namespaces: StatefulTable::new(),
+      clis: vec![],
+      node_metrics: vec![],
+      pods: StatefulTable::new(),
+      services: StatefulTable::new(),
+      nodes: StatefulTable::new(),
+    }
+  }
+
+  fn dispatch(&mut self, action: IoEvent) {
+    self.is_loading = true;
+    if self.io_tx.send(action).is_err() {
+      self.is_loading = false;
+    }
+  }
+
+  pub fn on_tick(&mut self, first_render: bool) {
+    if self.frozen {
+      return;
+    }
+
+    self.tick_count += 1;
+    if first_render || self.refresh || self.tick_count % self.tick_until_poll == 0 {
+      self.refresh = false;
+      self.dispatch(IoEvent::GetKubeConfig);
+      self.dispatch(IoEvent::GetNamespaces);
+      self.dispatch(IoEvent::GetPods);
+      self.dispatch(IoEvent::GetServices);
+      self.dispatch(IoEvent::GetNodes);
+      self.dispatch(IoEvent::GetMetrics);
+    }
+  }
+
+  /// Clear accumulated state (metrics, table selections, help-menu scroll) and
+  /// set `refresh` so the next tick re-fetches everything. The selected tab and
+  /// the `frozen`/`maximized` flags are kept; while `frozen` is set, the
+  /// re-fetch happens only once the app is unfrozen.
+  pub fn reset_data(&mut self) {
+    self.node_metrics.clear();
+    self.pods.state = TableState::default();
+    self.services.state = TableState::default();
+    self.nodes.state = TableState::default();
+    self.namespaces.state = TableState::default();
+    self.help_menu_offset = 0;
+    self.help_menu_page = 0;
+    self.refresh = true;
+  }
+}
@@ -0,0 +1,8 @@
+pub const BANNER: &str = r#"
+ _  ______   ___   _____ _  _
+| |/ /  _ \ / _ \ / ____| || |
+| ' /| | | | |_| | (___ | || |_
+|  < | | | |  _  |\___ \|__   _|
+| . \| |_| | | | |____) |  | |
+|_|\_\____/|_| |_|_____/   |_|
+"#;
@@ -0,0 +1,176 @@
+use std::{fs, path::PathBuf};
+
+use anyhow::{anyhow, Context, Result};
+use clap::{App as ClapApp, Arg, ArgMatches};
+use serde::{Deserialize, Serialize};
+
+use crate::ui::ThemePreset;
+
+/// Which resource tab `App` should open on by default. Keep this in sync with
+/// the tab order built in `App::new` (`Pods`, `Services`, `Nodes`).
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Resource {
+  Pods,
+  Services,
+  Nodes,
+}
+
+impl Default for Resource {
+  fn default() -> Self {
+    Resource::Pods
+  }
+}
+
+impl Resource {
+  pub fn tab_index(self) -> usize {
+    match self {
+      Resource::Pods => 0,
+      Resource::Services => 1,
+      Resource::Nodes => 2,
+    }
+  }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Cli {
+  pub tick_rate: u64,
+  pub poll_rate: u64,
+  pub enhanced_graphics: bool,
+  pub default_resource: Resource,
+  pub theme: ThemePreset,
+
+  /// Render a condensed dashboard with no gauges or info bar, suitable for
+  /// small terminals or log capture. Command-line only, not persisted.
+  #[serde(skip)]
+  pub basic: bool,
+}
+
+impl Default for Cli {
+  fn default() -> Self {
+    Cli {
+      tick_rate: 250,
+      poll_rate: 5000,
+      enhanced_graphics: true,
+      default_resource: Resource::default(),
+      theme: ThemePreset::default(),
+      basic: false,
+    }
+  }
+}
+
+impl Cli {
+  pub fn new() -> Cli {
+    Cli::default()
+  }
+
+  pub fn get_clap_app(&self) -> ClapApp<'static, 'static> {
+    ClapApp::new(env!("CARGO_PKG_NAME"))
+      .version(env!("CARGO_PKG_VERSION"))
+      .author(env!("CARGO_PKG_AUTHORS"))
+      .about(env!("CARGO_PKG_DESCRIPTION"))
+      .arg(
+        Arg::with_name("tick-rate")
+          .short("t")
+          .long("tick-rate")
+          .help("Set the UI tick rate in milliseconds: the display update rate")
+          .takes_value(true),
+      )
+      .arg(
+        Arg::with_name("poll-rate")
+          .short("p")
+          .long("poll-rate")
+          .help("Set the network poll rate in milliseconds (must be a multiple of tick-rate)")
+          .takes_value(true),
+      )
+      .arg(
+        Arg::with_name("config")
+          .short("C")
+          .long("config")
+          .value_name("PATH")
+          .help("Set the path to a TOML config file (created with defaults if missing)")
+          .takes_value(true),
+      )
+      .arg(
+        Arg::with_name("basic")
+          .short("b")
+          .long("basic")
+          .help("Run in basic mode: no gauges or info bar, just the resource tables"),
+      )
+  }
+
+  /// Path to the config file: the one passed on the command line, or the
+  /// default location under the user's config directory.
+  pub fn config_path(matches: &ArgMatches) -> Result<PathBuf> {
+    if let Some(path) = matches.value_of("config") {
+      return Ok(PathBuf::from(path));
+    }
+
+    let mut path = dirs_next::config_dir()
+      .ok_or_else(|| anyhow!("Unable to locate the user's config directory, pass --config explicitly"))?;
+    path.push("kdash");
+    path.push("config.toml");
+    Ok(path)
+  }
+
+  /// Load `Cli` from `path`, writing a default config file there first if none
+  /// exists yet.
+  pub fn load(path: &PathBuf) -> Result<Cli> {
+    if !path.exists() {
+      let default = Cli::default();
+      default.save(path)?;
+      return Ok(default);
+    }
+
+    let contents =
+      fs::read_to_string(path).with_context(|| format!("Unable to read config file {}", path.display()))?;
+    let cli: Cli =
+      toml::from_str(&contents).with_context(|| format!("Unable to parse config file {}", path.display()))?;
+    cli.validate()?;
+    Ok(cli)
+  }
+
+  pub fn save(&self, path: &PathBuf) -> Result<()> {
+    if let Some(parent) = path.parent() {
+      fs::create_dir_all(parent)
+        .with_context(|| format!("Unable to create config directory {}", parent.display()))?;
+    }
+    let contents = toml::to_string_pretty(self).context("Unable to serialize default config")?;
+    fs::write(path, contents).with_context(|| format!("Unable to write config file {}", path.display()))
+  }
+
+  /// Apply command-line overrides on top of the config-file (or default)
+  /// values, so the final precedence is CLI > config file > built-in default.
+  pub fn apply_matches(&mut self, matches: &ArgMatches) -> Result<()> {
+    if let Some(tick_rate) = matches.value_of("tick-rate").and_then(|v| v.parse().ok()) {
+      self.tick_rate = tick_rate;
+    }
+
+    if let Some(poll_rate) = matches.value_of("poll-rate").and_then(|v| v.parse().ok()) {
+      self.poll_rate = poll_rate;
+    }
+
+    if matches.is_present("basic") {
+      self.basic = true;
+    }
+
+    self.validate()
+  }
+
+  fn validate(&self) -> Result<()> {
+    if self.tick_rate == 0 {
+      return Err(anyhow!("Tick rate must be greater than 0"));
+    }
+    if self.tick_rate >= 1000 {
+      return Err(anyhow!("Tick rate must be below 1000"));
+    }
+    if self.poll_rate == 0 {
+      return Err(anyhow!("Poll rate must be greater than 0"));
+    }
+    if self.poll_rate % self.tick_rate != 0 {
+      return Err(anyhow!("Poll rate must be a multiple of tick-rate"));
+    }
+    Ok(())
+  }
+}
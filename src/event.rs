@@ -0,0 +1,112 @@
+use std::{
+  sync::mpsc,
+  thread,
+  time::{Duration, Instant},
+};
+
+use crossterm::event::{self, Event as CEvent, KeyCode, KeyModifiers};
+
+/// A normalized keypress, decoupled from crossterm's own `KeyEvent` so the rest
+/// of the app (`handlers::handle_app`) doesn't need to know about the terminal
+/// backend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Key {
+  Char(char),
+  Ctrl(char),
+  Esc,
+  Enter,
+  Left,
+  Right,
+  Up,
+  Down,
+  Unknown,
+}
+
+impl From<event::KeyEvent> for Key {
+  fn from(key_event: event::KeyEvent) -> Self {
+    match key_event {
+      event::KeyEvent {
+        code: KeyCode::Char(c),
+        modifiers: KeyModifiers::CONTROL,
+      } => Key::Ctrl(c),
+      event::KeyEvent {
+        code: KeyCode::Char(c),
+        ..
+      } => Key::Char(c),
+      event::KeyEvent {
+        code: KeyCode::Esc, ..
+      } => Key::Esc,
+      event::KeyEvent {
+        code: KeyCode::Enter,
+        ..
+      } => Key::Enter,
+      event::KeyEvent {
+        code: KeyCode::Left,
+        ..
+      } => Key::Left,
+      event::KeyEvent {
+        code: KeyCode::Right,
+        ..
+      } => Key::Right,
+      event::KeyEvent {
+        code: KeyCode::Up, ..
+      } => Key::Up,
+      event::KeyEvent {
+        code: KeyCode::Down,
+        ..
+      } => Key::Down,
+      _ => Key::Unknown,
+    }
+  }
+}
+
+pub enum Event<I> {
+  Input(I),
+  Tick,
+}
+
+/// Polls crossterm for key events on a background thread and merges them with a
+/// fixed-rate tick, both funneled through a single channel so `start_ui`'s loop
+/// can `next()` whichever comes first.
+pub struct Events {
+  rx: mpsc::Receiver<Event<Key>>,
+  _tx: mpsc::Sender<Event<Key>>,
+}
+
+impl Events {
+  pub fn new(tick_rate: u64) -> Events {
+    let (tx, rx) = mpsc::channel();
+    let event_tx = tx.clone();
+
+    thread::spawn(move || {
+      let tick_rate = Duration::from_millis(tick_rate);
+      let mut last_tick = Instant::now();
+      loop {
+        let timeout = tick_rate
+          .checked_sub(last_tick.elapsed())
+          .unwrap_or_else(|| Duration::from_secs(0));
+
+        if event::poll(timeout).unwrap() {
+          if let CEvent::Key(key) = event::read().unwrap() {
+            if event_tx.send(Event::Input(Key::from(key))).is_err() {
+              return;
+            }
+          }
+        }
+
+        if last_tick.elapsed() >= tick_rate {
+          if event_tx.send(Event::Tick).is_err() {
+            return;
+          }
+          last_tick = Instant::now();
+        }
+      }
+    });
+
+    Events { rx, _tx: tx }
+  }
+
+  pub fn next(&self) -> Result<Event<Key>, mpsc::RecvError> {
+    self.rx.recv()
+  }
+}
@@ -0,0 +1,18 @@
+use crate::app::App;
+use crate::event::Key;
+
+/// Translate a normalized keypress into an `App` mutation. Called from
+/// `start_ui`'s event loop for every `Event::Input` except Ctrl-C, which the
+/// loop handles itself by quitting.
+pub fn handle_app(key: Key, app: &mut App) {
+  match key {
+    Key::Char('q') => app.should_quit = true,
+    Key::Left | Key::Char('h') => app.context_tabs.previous(),
+    Key::Right | Key::Char('l') => app.context_tabs.next(),
+    Key::Char('f') => app.frozen = !app.frozen,
+    Key::Ctrl('r') => app.reset_data(),
+    Key::Char('m') => app.maximized = !app.maximized,
+    Key::Esc if app.maximized => app.maximized = false,
+    _ => {}
+  }
+}
@@ -10,6 +10,7 @@ use crate::event::Key;
 use app::App;
 use cli::Cli;
 use network::{get_client, IoEvent, Network};
+use ui::Theme;
 
 use anyhow::Result;
 use backtrace::Backtrace;
@@ -42,32 +43,48 @@ fn shutdown(mut terminal: Terminal<CrosstermBackend<Stdout>>) -> Result<()> {
   Ok(())
 }
 
+/// Restore the terminal to a usable state and print `message`, best-effort —
+/// called from both the panic hook and fatal non-panic errors, so a crash
+/// anywhere never leaves the user's terminal stuck in raw/alternate-screen mode.
+fn restore_terminal_and_print(message: &str) {
+  let _ = disable_raw_mode();
+  let _ = execute!(
+    io::stdout(),
+    LeaveAlternateScreen,
+    DisableMouseCapture,
+    Print(format!("{}\n\r", message))
+  );
+}
+
 fn panic_hook(info: &PanicInfo<'_>) {
-  if cfg!(debug_assertions) {
-    let location = info.location().unwrap();
-
-    let msg = match info.payload().downcast_ref::<&'static str>() {
-      Some(s) => *s,
-      None => match info.payload().downcast_ref::<String>() {
-        Some(s) => &s[..],
-        None => "Box<Any>",
-      },
-    };
+  let location = info.location().unwrap();
+
+  let msg = match info.payload().downcast_ref::<&'static str>() {
+    Some(s) => *s,
+    None => match info.payload().downcast_ref::<String>() {
+      Some(s) => &s[..],
+      None => "Box<Any>",
+    },
+  };
+
+  let stacktrace = if cfg!(debug_assertions) {
+    format!("\n\r{:?}", Backtrace::new()).replace('\n', "\n\r")
+  } else {
+    String::new()
+  };
+
+  restore_terminal_and_print(&format!(
+    "thread '<unnamed>' panicked at '{}', {}{}",
+    msg, location, stacktrace
+  ));
+}
 
-    let stacktrace: String = format!("{:?}", Backtrace::new()).replace('\n', "\n\r");
-
-    disable_raw_mode().unwrap();
-    execute!(
-      io::stdout(),
-      LeaveAlternateScreen,
-      Print(format!(
-        "thread '<unnamed>' panicked at '{}', {}\n\r{}",
-        msg, location, stacktrace
-      )),
-      DisableMouseCapture
-    )
-    .unwrap();
-  }
+/// Restore the terminal and exit with a readable message, for fatal errors
+/// that aren't recoverable but also aren't programming bugs worth a panic
+/// (e.g. the network thread couldn't reach Kubernetes at all).
+fn fatal_error(message: &str) -> ! {
+  restore_terminal_and_print(message);
+  std::process::exit(1);
 }
 
 #[tokio::main]
@@ -76,31 +93,13 @@ async fn main() -> Result<()> {
     panic_hook(info);
   }));
 
-  let mut cli: Cli = Cli::new();
-  let clap_app = cli.get_clap_app();
+  let clap_app = Cli::new().get_clap_app();
   let matches = clap_app.get_matches();
 
-  if let Some(tick_rate) = matches
-    .value_of("tick-rate")
-    .and_then(|tick_rate| tick_rate.parse().ok())
-  {
-    if tick_rate >= 1000 {
-      panic!("Tick rate must be below 1000");
-    } else {
-      cli.tick_rate = tick_rate;
-    }
-  }
-
-  if let Some(poll_rate) = matches
-    .value_of("poll-rate")
-    .and_then(|poll_rate| poll_rate.parse().ok())
-  {
-    if (poll_rate % cli.tick_rate) > 0u64 {
-      panic!("Poll rate must be multiple of tick-rate");
-    } else {
-      cli.poll_rate = poll_rate;
-    }
-  }
+  // CLI > config file > built-in default
+  let config_path = Cli::config_path(&matches)?;
+  let mut cli = Cli::load(&config_path)?;
+  cli.apply_matches(&matches)?;
 
   let (sync_io_tx, sync_io_rx) = mpsc::channel::<IoEvent>();
 
@@ -109,6 +108,9 @@ async fn main() -> Result<()> {
     sync_io_tx,
     cli.enhanced_graphics,
     cli.poll_rate / cli.tick_rate,
+    cli.default_resource,
+    cli.basic,
+    Theme::from_preset(cli.theme),
   )));
 
   let cloned_app = Arc::clone(&app);
@@ -134,7 +136,7 @@ async fn start_tokio<'a>(io_rx: mpsc::Receiver<IoEvent>, app: &Arc<Mutex<App>>)
         network.handle_network_event(io_event).await;
       }
     }
-    Err(e) => panic!("Unable to obtain Kubernetes client {}", e),
+    Err(e) => fatal_error(&format!("Unable to obtain Kubernetes client: {}", e)),
   }
 }
 
@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use kube::Client;
+use tokio::sync::Mutex;
+
+use crate::app::App;
+
+/// Work items dispatched from the UI thread to the network thread. `App::on_tick`
+/// sends these down `App::io_tx`; `Network::handle_network_event` is the receiving
+/// end. Its per-resource handlers are placeholders that make no Kubernetes API
+/// calls and leave the shared `App`'s data untouched; handling an event only
+/// clears `App::is_loading`.
+#[derive(Clone, Debug)]
+pub enum IoEvent {
+  GetKubeConfig,
+  GetNamespaces,
+  GetPods,
+  GetServices,
+  GetNodes,
+  GetMetrics,
+}
+
+pub async fn get_client() -> Result<Client> {
+  let client = Client::try_default().await?;
+  Ok(client)
+}
+
+pub struct Network<'a> {
+  client: Client,
+  app: &'a Arc<Mutex<App>>,
+}
+
+impl<'a> Network<'a> {
+  pub fn new(client: Client, app: &'a Arc<Mutex<App>>) -> Self {
+    Network { client, app }
+  }
+
+  pub async fn handle_network_event(&mut self, io_event: IoEvent) {
+    match io_event {
+      IoEvent::GetKubeConfig => self.get_kube_config().await,
+      IoEvent::GetNamespaces => self.get_namespaces().await,
+      IoEvent::GetPods => self.get_pods().await,
+      IoEvent::GetServices => self.get_services().await,
+      IoEvent::GetNodes => self.get_nodes().await,
+      IoEvent::GetMetrics => self.get_metrics().await,
+    }
+
+    let mut app = self.app.lock().await;
+    app.is_loading = false;
+  }
+
+  async fn get_kube_config(&self) {
+    // placeholder for active-context lookup against `self.client`'s kubeconfig
+  }
+
+  async fn get_namespaces(&self) {
+    // placeholder for a `self.client` namespace list call
+  }
+
+  async fn get_pods(&self) {
+    // placeholder for a `self.client` pod list call
+  }
+
+  async fn get_services(&self) {
+    // placeholder for a `self.client` service list call
+  }
+
+  async fn get_nodes(&self) {
+    // placeholder for a `self.client` node list call
+  }
+
+  async fn get_metrics(&self) {
+    // placeholder for a metrics-server query
+  }
+}
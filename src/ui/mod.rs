@@ -0,0 +1,15 @@
+mod overview;
+mod utils;
+
+use tui::{backend::Backend, Frame};
+
+use crate::app::App;
+
+pub use utils::{Theme, ThemePreset};
+
+pub const HIGHLIGHT: &str = "=> ";
+
+pub fn draw<B: Backend>(f: &mut Frame<B>, app: &mut App) {
+  let size = f.size();
+  overview::draw_overview(f, app, size);
+}
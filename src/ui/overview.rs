@@ -1,8 +1,6 @@
 use super::utils::{
-  draw_placeholder, get_gauge_style, horizontal_chunks, layout_block_default,
-  layout_block_top_border, loading, style_failure, style_highlight, style_primary, style_secondary,
-  style_success, table_header_style, title_style_secondary, vertical_chunks,
-  vertical_chunks_with_margin,
+  draw_placeholder, get_gauge_style, horizontal_chunks, layout_block_default, layout_block_top_border, loading,
+  vertical_chunks, vertical_chunks_with_margin,
 };
 use super::HIGHLIGHT;
 use crate::app::{App, NodeMetrics};
@@ -16,7 +14,13 @@ use tui::{
 };
 
 pub fn draw_overview<B: Backend>(f: &mut Frame<B>, app: &mut App, area: Rect) {
-  if app.show_info_bar {
+  if app.maximized {
+    draw_resource_tab(f, app, area);
+  } else if app.basic {
+    let chunks = vertical_chunks(vec![Constraint::Length(1), Constraint::Min(10)], area);
+    draw_basic_status(f, app, chunks[0]);
+    draw_active_context_tabs(f, app, chunks[1]);
+  } else if app.show_info_bar {
     let chunks = vertical_chunks(vec![Constraint::Length(9), Constraint::Min(10)], area);
     draw_status(f, app, chunks[0]);
     draw_active_context_tabs(f, app, chunks[1]);
@@ -25,6 +29,22 @@ pub fn draw_overview<B: Backend>(f: &mut Frame<B>, app: &mut App, area: Rect) {
   }
 }
 
+/// Single-line stand-in for `draw_status`'s gauges, used in basic mode.
+fn draw_basic_status<B: Backend>(f: &mut Frame<B>, app: &mut App, area: Rect) {
+  let cpu_pct = (get_nm_ratio(app.node_metrics.as_ref(), |acc, nm| acc + nm.cpu_percent_i) * 100f64) as u16;
+  let mem_pct = (get_nm_ratio(app.node_metrics.as_ref(), |acc, nm| acc + nm.mem_percent_i) * 100f64) as u16;
+
+  let text = Spans::from(vec![
+    Span::styled("CPU ", app.theme.style_secondary()),
+    Span::styled(format!("{}%", cpu_pct), app.theme.style_primary()),
+    Span::raw(" | "),
+    Span::styled("MEM ", app.theme.style_secondary()),
+    Span::styled(format!("{}%", mem_pct), app.theme.style_primary()),
+    Span::styled(frozen_indicator(app.frozen), app.theme.style_failure()),
+  ]);
+  f.render_widget(Paragraph::new(text), area);
+}
+
 fn draw_status<B: Backend>(f: &mut Frame<B>, app: &mut App, area: Rect) {
   let chunks = horizontal_chunks(
     vec![
@@ -45,17 +65,18 @@ fn draw_status<B: Backend>(f: &mut Frame<B>, app: &mut App, area: Rect) {
 fn draw_logo<B: Backend>(f: &mut Frame<B>, app: &mut App, area: Rect) {
   // Banner text with correct styling
   let text = format!(
-    "{}\nv{} with ♥ in Rust {}",
+    "{}\nv{} with ♥ in Rust {}{}",
     BANNER,
     env!("CARGO_PKG_VERSION"),
-    nw_loading_indicator(app.is_loading)
+    nw_loading_indicator(app.is_loading),
+    frozen_indicator(app.frozen)
   );
   let mut text = Text::from(text);
-  text.patch_style(style_success());
+  text.patch_style(app.theme.style_success());
 
   // Contains the banner
   let paragraph = Paragraph::new(text)
-    .style(style_success())
+    .style(app.theme.style_success())
     .block(Block::default().borders(Borders::ALL));
   f.render_widget(paragraph, area);
 }
@@ -68,14 +89,23 @@ fn nw_loading_indicator<'a>(loading: bool) -> &'a str {
   }
 }
 
+fn frozen_indicator<'a>(frozen: bool) -> &'a str {
+  if frozen {
+    " [FROZEN]"
+  } else {
+    ""
+  }
+}
+
 fn draw_cli_status<B: Backend>(f: &mut Frame<B>, app: &mut App, area: Rect) {
-  let block = layout_block_default("CLI Info");
+  let theme = app.theme;
+  let block = layout_block_default("CLI Info", &theme);
   if !app.clis.is_empty() {
     let rows = app.clis.iter().map(|s| {
       let style = if s.status {
-        style_success()
+        theme.style_success()
       } else {
-        style_failure()
+        theme.style_failure()
       };
       Row::new(vec![
         Cell::from(s.name.as_ref()),
@@ -89,7 +119,7 @@ fn draw_cli_status<B: Backend>(f: &mut Frame<B>, app: &mut App, area: Rect) {
       .widths(&[Constraint::Percentage(50), Constraint::Percentage(50)]);
     f.render_widget(table, area);
   } else {
-    loading(f, block, area, app.is_loading);
+    loading(f, block, area, app.is_loading, &theme);
   }
 }
 
@@ -97,24 +127,30 @@ fn draw_active_context_tabs<B: Backend>(f: &mut Frame<B>, app: &mut App, area: R
   let chunks =
     vertical_chunks_with_margin(vec![Constraint::Length(2), Constraint::Min(0)], area, 1);
 
+  let theme = app.theme;
   let titles = app
     .context_tabs
     .titles
     .iter()
-    .map(|t| Spans::from(Span::styled(*t, style_success())))
+    .map(|t| Spans::from(Span::styled(*t, theme.style_success())))
     .collect();
   let tabs = Tabs::new(titles)
-    .block(layout_block_default("Resources"))
-    .highlight_style(style_secondary())
+    .block(layout_block_default("Resources", &theme))
+    .highlight_style(theme.style_secondary())
     .select(app.context_tabs.index);
 
   f.render_widget(tabs, area);
-  // render tab content
+  draw_resource_tab(f, app, chunks[1]);
+}
+
+/// Render whichever resource table `context_tabs.index` currently selects.
+/// Shared between the normal tab-bar layout and the maximized full-area one.
+fn draw_resource_tab<B: Backend>(f: &mut Frame<B>, app: &mut App, area: Rect) {
   match app.context_tabs.index {
-    0 => draw_pods(f, app, chunks[1]),
-    1 => draw_services(f, app, chunks[1]),
-    2 => draw_nodes(f, app, chunks[1]),
-    3..=7 => draw_placeholder(f, chunks[1]),
+    0 => draw_pods(f, app, area),
+    1 => draw_services(f, app, area),
+    2 => draw_nodes(f, app, area),
+    3..=7 => draw_placeholder(f, area, &app.theme),
     _ => {}
   };
 }
@@ -130,7 +166,7 @@ fn draw_context_info<B: Backend>(f: &mut Frame<B>, app: &mut App, area: Rect) {
     1,
   );
 
-  let block = layout_block_default("Context Info");
+  let block = layout_block_default("Context Info", &app.theme);
 
   f.render_widget(block, area);
 
@@ -139,23 +175,23 @@ fn draw_context_info<B: Backend>(f: &mut Frame<B>, app: &mut App, area: Rect) {
     Some(active_context) => {
       text = vec![
         Spans::from(vec![
-          Span::styled("Context: ", style_secondary()),
-          Span::styled(&active_context.name, style_primary()),
+          Span::styled("Context: ", app.theme.style_secondary()),
+          Span::styled(&active_context.name, app.theme.style_primary()),
         ]),
         Spans::from(vec![
-          Span::styled("Cluster: ", style_secondary()),
-          Span::styled(&active_context.cluster, style_primary()),
+          Span::styled("Cluster: ", app.theme.style_secondary()),
+          Span::styled(&active_context.cluster, app.theme.style_primary()),
         ]),
         Spans::from(vec![
-          Span::styled("User: ", style_secondary()),
-          Span::styled(&active_context.user, style_primary()),
+          Span::styled("User: ", app.theme.style_secondary()),
+          Span::styled(&active_context.user, app.theme.style_primary()),
         ]),
       ];
     }
     None => {
       text = vec![Spans::from(Span::styled(
         "Context information not found",
-        style_failure(),
+        app.theme.style_failure(),
       ))]
     }
   }
@@ -164,8 +200,8 @@ fn draw_context_info<B: Backend>(f: &mut Frame<B>, app: &mut App, area: Rect) {
   f.render_widget(paragraph, chunks[0]);
 
   let cpu_gauge = LineGauge::default()
-    .block(Block::default().title(title_style_secondary("CPU:")))
-    .gauge_style(style_primary())
+    .block(Block::default().title(app.theme.title_style_secondary("CPU:")))
+    .gauge_style(app.theme.style_primary())
     .line_set(get_gauge_style(app.enhanced_graphics))
     .ratio(get_nm_ratio(app.node_metrics.as_ref(), |acc, nm| {
       acc + nm.cpu_percent_i
@@ -173,8 +209,8 @@ fn draw_context_info<B: Backend>(f: &mut Frame<B>, app: &mut App, area: Rect) {
   f.render_widget(cpu_gauge, chunks[1]);
 
   let mem_gauge = LineGauge::default()
-    .block(Block::default().title(title_style_secondary("Memory:")))
-    .gauge_style(style_primary())
+    .block(Block::default().title(app.theme.title_style_secondary("Memory:")))
+    .gauge_style(app.theme.style_primary())
     .line_set(get_gauge_style(app.enhanced_graphics))
     .ratio(get_nm_ratio(app.node_metrics.as_ref(), |acc, nm| {
       acc + nm.mem_percent_i
@@ -187,7 +223,8 @@ fn draw_namespaces<B: Backend>(f: &mut Frame<B>, app: &mut App, area: Rect) {
     "Namespaces <n> (selected: {})",
     app.selected_ns.as_ref().unwrap_or(&String::from("all"))
   );
-  let block = layout_block_default(title.as_str());
+  let theme = app.theme;
+  let block = layout_block_default(title.as_str(), &theme);
 
   if !app.namespaces.items.is_empty() {
     let rows = app.namespaces.items.iter().map(|c| {
@@ -195,29 +232,31 @@ fn draw_namespaces<B: Backend>(f: &mut Frame<B>, app: &mut App, area: Rect) {
         Cell::from(c.name.as_ref()),
         Cell::from(c.status.as_ref()),
       ])
-      .style(style_primary())
+      .style(theme.style_primary())
     });
 
     let table = Table::new(rows)
-      .header(table_header_style(vec!["Name", "Status"]))
+      .header(theme.table_header_style(vec!["Name", "Status"]))
       .block(block)
-      .highlight_style(style_highlight())
+      .highlight_style(theme.style_highlight())
       .highlight_symbol(HIGHLIGHT)
       .widths(&[Constraint::Percentage(80), Constraint::Percentage(20)]);
 
     f.render_stateful_widget(table, area, &mut app.namespaces.state);
   } else {
-    loading(f, block, area, app.is_loading);
+    loading(f, block, area, app.is_loading, &theme);
   }
 }
 
 fn draw_pods<B: Backend>(f: &mut Frame<B>, app: &mut App, area: Rect) {
   let title = format!(
-    "Pods ({}) [{}]",
+    "Pods ({}) [{}]{}",
     app.selected_ns.as_ref().unwrap_or(&String::from("all")),
-    app.pods.items.len()
+    app.pods.items.len(),
+    frozen_indicator(app.frozen)
   );
-  let block = layout_block_top_border(title.as_str());
+  let theme = app.theme;
+  let block = layout_block_top_border(title.as_str(), &theme);
 
   if !app.pods.items.is_empty() {
     let rows = app.pods.items.iter().map(|c| {
@@ -229,11 +268,11 @@ fn draw_pods<B: Backend>(f: &mut Frame<B>, app: &mut App, area: Rect) {
         Cell::from(c.restarts.to_string()),
         Cell::from(c.age.as_ref()),
       ])
-      .style(style_primary())
+      .style(theme.style_primary())
     });
 
     let table = Table::new(rows)
-      .header(table_header_style(vec![
+      .header(theme.table_header_style(vec![
         "Namespace",
         "Name",
         "Ready",
@@ -242,7 +281,7 @@ fn draw_pods<B: Backend>(f: &mut Frame<B>, app: &mut App, area: Rect) {
         "Age",
       ]))
       .block(block)
-      .highlight_style(style_highlight())
+      .highlight_style(theme.style_highlight())
       .highlight_symbol(HIGHLIGHT)
       .widths(&[
         Constraint::Percentage(25),
@@ -255,13 +294,14 @@ fn draw_pods<B: Backend>(f: &mut Frame<B>, app: &mut App, area: Rect) {
 
     f.render_stateful_widget(table, area, &mut app.pods.state);
   } else {
-    loading(f, block, area, app.is_loading);
+    loading(f, block, area, app.is_loading, &theme);
   }
 }
 
 fn draw_nodes<B: Backend>(f: &mut Frame<B>, app: &mut App, area: Rect) {
-  let title = format!("Nodes [{}]", app.nodes.items.len());
-  let block = layout_block_top_border(title.as_str());
+  let title = format!("Nodes [{}]{}", app.nodes.items.len(), frozen_indicator(app.frozen));
+  let theme = app.theme;
+  let block = layout_block_top_border(title.as_str(), &theme);
 
   if !app.nodes.items.is_empty() {
     let rows = app.nodes.items.iter().map(|c| {
@@ -278,15 +318,15 @@ fn draw_nodes<B: Backend>(f: &mut Frame<B>, app: &mut App, area: Rect) {
         Cell::from(c.mem_percent.as_ref()),
         Cell::from(c.age.as_ref()),
       ])
-      .style(style_primary())
+      .style(theme.style_primary())
     });
 
     let table = Table::new(rows)
-      .header(table_header_style(vec![
+      .header(theme.table_header_style(vec![
         "Name", "Status", "Roles", "Version", "Pods", "CPU", "Mem", "CPU %", "Mem %", "Age",
       ]))
       .block(block)
-      .highlight_style(style_highlight())
+      .highlight_style(theme.style_highlight())
       .highlight_symbol(HIGHLIGHT)
       .widths(&[
         Constraint::Percentage(30),
@@ -303,17 +343,19 @@ fn draw_nodes<B: Backend>(f: &mut Frame<B>, app: &mut App, area: Rect) {
 
     f.render_stateful_widget(table, area, &mut app.nodes.state);
   } else {
-    loading(f, block, area, app.is_loading);
+    loading(f, block, area, app.is_loading, &theme);
   }
 }
 
 fn draw_services<B: Backend>(f: &mut Frame<B>, app: &mut App, area: Rect) {
   let title = format!(
-    "Services ({}) [{}]",
+    "Services ({}) [{}]{}",
     app.selected_ns.as_ref().unwrap_or(&String::from("all")),
-    app.services.items.len()
+    app.services.items.len(),
+    frozen_indicator(app.frozen)
   );
-  let block = layout_block_top_border(title.as_str());
+  let theme = app.theme;
+  let block = layout_block_top_border(title.as_str(), &theme);
 
   if !app.services.items.is_empty() {
     let rows = app.services.items.iter().map(|c| {
@@ -326,11 +368,11 @@ fn draw_services<B: Backend>(f: &mut Frame<B>, app: &mut App, area: Rect) {
         Cell::from(c.ports.as_ref()),
         Cell::from(c.age.as_ref()),
       ])
-      .style(style_primary())
+      .style(theme.style_primary())
     });
 
     let table = Table::new(rows)
-      .header(table_header_style(vec![
+      .header(theme.table_header_style(vec![
         "Namespace",
         "Name",
         "Type",
@@ -340,7 +382,7 @@ fn draw_services<B: Backend>(f: &mut Frame<B>, app: &mut App, area: Rect) {
         "Age",
       ]))
       .block(block)
-      .highlight_style(style_highlight())
+      .highlight_style(theme.style_highlight())
       .highlight_symbol(HIGHLIGHT)
       .widths(&[
         Constraint::Percentage(10),
@@ -354,7 +396,7 @@ fn draw_services<B: Backend>(f: &mut Frame<B>, app: &mut App, area: Rect) {
 
     f.render_stateful_widget(table, area, &mut app.services.state);
   } else {
-    loading(f, block, area, app.is_loading);
+    loading(f, block, area, app.is_loading, &theme);
   }
 }
 
@@ -0,0 +1,145 @@
+use serde::{Deserialize, Serialize};
+use tui::{
+  backend::Backend,
+  layout::{Constraint, Direction, Layout, Rect},
+  style::{Color, Modifier, Style},
+  symbols::line,
+  text::Span,
+  widgets::{Block, Borders, Cell, Paragraph, Row},
+  Frame,
+};
+
+/// Named, built-in color schemes selectable from the config file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ThemePreset {
+  Default,
+  Monochrome,
+}
+
+impl Default for ThemePreset {
+  fn default() -> Self {
+    ThemePreset::Default
+  }
+}
+
+/// The colors used throughout the dashboard, loaded from the config file so
+/// users can recolor it for their terminal. `style_*` methods mirror the free
+/// functions the rest of `ui` used to call directly.
+#[derive(Clone, Copy, Debug)]
+pub struct Theme {
+  pub primary: Color,
+  pub secondary: Color,
+  pub success: Color,
+  pub failure: Color,
+  pub highlight: Color,
+}
+
+impl Default for Theme {
+  fn default() -> Self {
+    Theme::from_preset(ThemePreset::default())
+  }
+}
+
+impl Theme {
+  pub fn from_preset(preset: ThemePreset) -> Theme {
+    match preset {
+      ThemePreset::Default => Theme {
+        primary: Color::Cyan,
+        secondary: Color::Yellow,
+        success: Color::Green,
+        failure: Color::Red,
+        highlight: Color::Magenta,
+      },
+      ThemePreset::Monochrome => Theme {
+        primary: Color::White,
+        secondary: Color::Gray,
+        success: Color::White,
+        failure: Color::White,
+        highlight: Color::White,
+      },
+    }
+  }
+
+  pub fn style_primary(&self) -> Style {
+    Style::default().fg(self.primary)
+  }
+
+  pub fn style_secondary(&self) -> Style {
+    Style::default().fg(self.secondary)
+  }
+
+  pub fn style_success(&self) -> Style {
+    Style::default().fg(self.success)
+  }
+
+  pub fn style_failure(&self) -> Style {
+    Style::default().fg(self.failure)
+  }
+
+  pub fn style_highlight(&self) -> Style {
+    Style::default().fg(self.highlight).add_modifier(Modifier::BOLD)
+  }
+
+  pub fn title_style_secondary<'a>(&self, txt: &'a str) -> Span<'a> {
+    Span::styled(txt, self.style_secondary())
+  }
+
+  pub fn table_header_style(&self, columns: Vec<&'static str>) -> Row<'static> {
+    Row::new(columns.into_iter().map(Cell::from)).style(self.style_secondary())
+  }
+}
+
+pub fn layout_block_default<'a>(title: &'a str, theme: &Theme) -> Block<'a> {
+  Block::default()
+    .borders(Borders::ALL)
+    .title(theme.title_style_secondary(title))
+}
+
+pub fn layout_block_top_border<'a>(title: &'a str, theme: &Theme) -> Block<'a> {
+  Block::default()
+    .borders(Borders::TOP)
+    .title(theme.title_style_secondary(title))
+}
+
+pub fn vertical_chunks(constraints: Vec<Constraint>, area: Rect) -> Vec<Rect> {
+  Layout::default()
+    .direction(Direction::Vertical)
+    .constraints(constraints)
+    .split(area)
+}
+
+pub fn vertical_chunks_with_margin(constraints: Vec<Constraint>, area: Rect, margin: u16) -> Vec<Rect> {
+  Layout::default()
+    .direction(Direction::Vertical)
+    .constraints(constraints)
+    .margin(margin)
+    .split(area)
+}
+
+pub fn horizontal_chunks(constraints: Vec<Constraint>, area: Rect) -> Vec<Rect> {
+  Layout::default()
+    .direction(Direction::Horizontal)
+    .constraints(constraints)
+    .split(area)
+}
+
+pub fn get_gauge_style(enhanced_graphics: bool) -> line::Set {
+  if enhanced_graphics {
+    line::THICK
+  } else {
+    line::NORMAL
+  }
+}
+
+pub fn loading<B: Backend>(f: &mut Frame<B>, block: Block, area: Rect, is_loading: bool, theme: &Theme) {
+  let text = if is_loading { "Loading..." } else { "No data available" };
+  let paragraph = Paragraph::new(text).style(theme.style_secondary()).block(block);
+  f.render_widget(paragraph, area);
+}
+
+pub fn draw_placeholder<B: Backend>(f: &mut Frame<B>, area: Rect, theme: &Theme) {
+  let block = layout_block_top_border("Not implemented yet", theme);
+  let paragraph = Paragraph::new("").block(block);
+  f.render_widget(paragraph, area);
+}